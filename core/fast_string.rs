@@ -3,10 +3,20 @@
 use std::borrow::{Borrow, Cow};
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::ops::Bound;
+use std::ops::RangeBounds;
 use std::sync::Arc;
 use url::Url;
 use v8::NewStringType;
 
+/// Paired UTF-8 text and its UTF-16 transcoding, referenced via [`FastString::StaticUtf16`].
+/// Keeping both behind one thin pointer (instead of storing the tuple inline) keeps that
+/// variant's payload to a single pointer-width.
+pub struct Utf16Static {
+  pub utf8: &'static str,
+  pub utf16: &'static [u16],
+}
+
 /// Module code can be sourced from strings or bytes that are either owned or borrowed. This enumeration allows us
 /// to perform a minimal amount of cloning and format-shifting of the underlying data.
 ///
@@ -28,8 +38,26 @@ pub enum FastString {
   /// Created from static data, known to contain only ASCII chars.
   StaticAscii(&'static str),
 
-  // Scripts loaded from the `deno_graph` infrastructure.
-  Arc(Arc<str>),
+  /// Created from static data containing non-ASCII chars. `v8()` hands the UTF-16
+  /// buffer straight to V8's two-byte external string path instead of forcing V8 to
+  /// re-decode UTF-8 on every instantiation, while `as_str`/`as_bytes` stay zero-cost.
+  /// Stored behind a single thin reference (rather than the two fat pointers a
+  /// `(&'static str, &'static [u16])` tuple would cost) so this variant doesn't blow
+  /// up `size_of::<FastString>()`.
+  StaticUtf16(&'static Utf16Static),
+
+  // Scripts loaded from the `deno_graph` infrastructure. `start`/`end` allow this
+  // variant to also represent a slice of `data`, so slicing an `Arc`-backed
+  // [`FastString`] never needs to reallocate: it just bumps the refcount and narrows
+  // the range.
+  //
+  // NOTE: this used to be the tuple variant `Arc(Arc<str>)`. Any match on the old
+  // shape elsewhere in the crate needs updating to these named fields.
+  Arc { data: Arc<str>, start: u32, end: u32 },
+
+  /// Created from short dynamic data (e.g. module specifiers, error fragments) that
+  /// fits in [`Self::INLINE_MAX`] bytes, avoiding a heap `Arc<str>` allocation entirely.
+  Inline { len: u8, buf: [u8; Self::INLINE_MAX] },
 }
 
 pub trait IsPotentiallyOwned {
@@ -43,6 +71,10 @@ impl IsPotentiallyOwned for String {
 }
 
 impl FastString {
+  /// Largest byte length that can be stored inline, chosen so the `Inline` variant
+  /// doesn't grow the enum beyond its other variants.
+  const INLINE_MAX: usize = 22;
+
   /// Compiler-time function to determine if a string is ASCII. Note that UTF-8 chars
   /// longer than one byte have the high-bit set and thus, are not ASCII.
   const fn is_ascii(s: &'static [u8]) -> bool {
@@ -72,16 +104,114 @@ impl FastString {
     }
   }
 
+  /// Creates a [`FastString`] from static bytes, validating that they're UTF-8 and
+  /// taking the ASCII fast path (like [`Self::ensure_static_ascii`]) when possible.
+  /// Panics if `bytes` isn't valid UTF-8.
+  pub fn from_static_bytes(bytes: &'static [u8]) -> Self {
+    let s = std::str::from_utf8(bytes)
+      .expect("from_static_bytes given bytes that were not valid UTF-8");
+    Self::from_static(s)
+  }
+
   pub fn from_ownable(s: impl IsPotentiallyOwned) -> Self {
     let s = s.maybe_into_owned_vec();
     match s {
-      Cow::Owned(s) => Self::Arc(s.into_boxed_str().into()),
+      Cow::Owned(s) => Self::try_inline(&s)
+        .unwrap_or_else(|| Self::from_arc(s.into_boxed_str().into())),
       Cow::Borrowed(s) => Self::from_static(s),
     }
   }
 
-  pub fn from_arc(s: Arc<str>) -> Self {
-    Self::Arc(s)
+  /// Returns an `Inline` variant if `s` fits in [`Self::INLINE_MAX`] bytes.
+  fn try_inline(s: &str) -> Option<Self> {
+    if s.len() > Self::INLINE_MAX {
+      return None;
+    }
+    let mut buf = [0; Self::INLINE_MAX];
+    buf[..s.len()].copy_from_slice(s.as_bytes());
+    Some(Self::Inline { len: s.len() as u8, buf })
+  }
+
+  pub fn from_arc(data: Arc<str>) -> Self {
+    let end = u32::try_from(data.len())
+      .expect("FastString::from_arc: string is longer than u32::MAX bytes");
+    Self::Arc { data, start: 0, end }
+  }
+
+  /// Creates a [`FastString`] from raw bytes (e.g. a filesystem read), substituting
+  /// U+FFFD for any invalid UTF-8 sequences. Input that is already valid UTF-8 skips the
+  /// lossy-decoding pass, but is then stored like `From<String>` does: copied inline
+  /// when short, otherwise copied into a new `Arc<str>`, so the buffer is not reused.
+  pub fn from_bytes_lossy(bytes: impl Into<Vec<u8>>) -> FastString {
+    match String::from_utf8(bytes.into()) {
+      Ok(s) => s.into(),
+      Err(e) => String::from_utf8_lossy(&e.into_bytes()).into_owned().into(),
+    }
+  }
+
+  /// Creates a [`FastString`] from raw bytes, returning `Err` if they aren't valid
+  /// UTF-8 rather than panicking or substituting replacement characters.
+  pub fn from_utf8(bytes: Vec<u8>) -> Result<FastString, std::str::Utf8Error> {
+    String::from_utf8(bytes)
+      .map(Into::into)
+      .map_err(|e| e.utf8_error())
+  }
+
+  /// Creates a [`FastString`] from static non-ASCII data, given both the original
+  /// UTF-8 text and its UTF-16 transcoding (typically produced by [`include_fast_utf16_string!`]).
+  pub const fn from_static_utf16(data: &'static Utf16Static) -> Self {
+    Self::StaticUtf16(data)
+  }
+
+  /// Returns a new [`FastString`] for the given byte range of `self`. For the `Arc`
+  /// variant this shares the same backing allocation (just bumping the refcount), and
+  /// `Static`/`StaticAscii` slices stay static, so neither copies. A `StaticUtf16` slice
+  /// is copied into an owned string, and an `Inline` slice copies its small buffer.
+  ///
+  /// Panics if the range is out of bounds or doesn't fall on a UTF-8 char boundary,
+  /// matching the behavior of slicing a `&str`.
+  pub fn slice(&self, range: impl RangeBounds<usize>) -> FastString {
+    let len = self.as_bytes().len();
+    let start = match range.start_bound() {
+      Bound::Included(&n) => n,
+      Bound::Excluded(&n) => n + 1,
+      Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+      Bound::Included(&n) => n + 1,
+      Bound::Excluded(&n) => n,
+      Bound::Unbounded => len,
+    };
+    // This both validates the range and enforces the char-boundary requirement.
+    let _ = &self.as_str()[start..end];
+    match self {
+      Self::Arc {
+        data,
+        start: self_start,
+        ..
+      } => {
+        let start_offset = u32::try_from(start)
+          .expect("FastString::slice: offset is longer than u32::MAX bytes");
+        let end_offset = u32::try_from(end)
+          .expect("FastString::slice: offset is longer than u32::MAX bytes");
+        Self::Arc {
+          data: data.clone(),
+          start: *self_start + start_offset,
+          end: *self_start + end_offset,
+        }
+      }
+      Self::Static(s) => Self::Static(&s[start..end]),
+      Self::StaticAscii(s) => Self::StaticAscii(&s[start..end]),
+      // There's no `'static` UTF-16 buffer for an arbitrary sub-range, so (like the
+      // `Arc` variant used to before it got the O(1) path above) we just take
+      // ownership of the slice; this allocates but never leaks.
+      Self::StaticUtf16(data) => data.utf8[start..end].to_owned().into(),
+      Self::Inline { buf, .. } => {
+        let mut new_buf = [0; Self::INLINE_MAX];
+        new_buf[..end - start].copy_from_slice(&buf[start..end]);
+        Self::Inline { len: (end - start) as u8, buf: new_buf }
+      }
+    }
   }
 
   pub const fn try_static_ascii(&self) -> Option<&'static [u8]> {
@@ -94,18 +224,24 @@ impl FastString {
   pub fn as_bytes(&self) -> &[u8] {
     // TODO(mmastrac): This can be const eventually
     match self {
-      Self::Arc(s) => s.as_bytes(),
+      Self::Arc { data, start, end } => {
+        &data.as_bytes()[*start as usize..*end as usize]
+      }
       Self::Static(s) => s.as_bytes(),
       Self::StaticAscii(s) => s.as_bytes(),
+      Self::StaticUtf16(data) => data.utf8.as_bytes(),
+      Self::Inline { len, buf } => &buf[..*len as usize],
     }
   }
 
   pub fn as_str(&self) -> &str {
     // TODO(mmastrac): This can be const eventually
     match self {
-      Self::Arc(s) => s,
+      Self::Arc { data, start, end } => &data[*start as usize..*end as usize],
       Self::Static(s) => s,
       Self::StaticAscii(s) => s,
+      Self::StaticUtf16(data) => data.utf8,
+      Self::Inline { .. } => std::str::from_utf8(self.as_bytes()).unwrap(),
     }
   }
 
@@ -113,22 +249,113 @@ impl FastString {
     &self,
     scope: &mut v8::HandleScope<'a>,
   ) -> v8::Local<'a, v8::String> {
-    match self.try_static_ascii() {
-      Some(s) => v8::String::new_external_onebyte_static(scope, s).unwrap(),
-      None => {
+    match self {
+      Self::StaticAscii(s) => {
+        v8::String::new_external_onebyte_static(scope, s.as_bytes()).unwrap()
+      }
+      Self::StaticUtf16(data) => {
+        v8::String::new_external_twobyte_static(scope, data.utf16).unwrap()
+      }
+      // Inline strings are tiny, so a copy is cheap; skip the UTF-8 decode when possible.
+      Self::Inline { .. } if self.as_bytes().is_ascii() => {
+        v8::String::new_from_one_byte(scope, self.as_bytes(), NewStringType::Normal)
+          .unwrap()
+      }
+      _ => {
         v8::String::new_from_utf8(scope, self.as_bytes(), NewStringType::Normal)
           .unwrap()
       }
     }
   }
 
-  /// Truncates a `ModuleCode`] value, possibly re-allocating or memcpy'ing. May be slow.
+  /// Truncates a [`FastString`] value. For the `Arc` variant this is O(1): it just
+  /// lowers `end`, since the variant already supports pointing at a sub-range of `data`.
   pub fn truncate(&mut self, index: usize) {
+    // Validate the char boundary up front, same as a normal string slice would.
+    let _ = &self.as_str()[..index];
     match self {
       Self::Static(b) => *self = Self::Static(&b[..index]),
       Self::StaticAscii(b) => *self = Self::StaticAscii(&b[..index]),
-      // We can't do much if we have an Arc<str>, so we'll just take ownership of the truncated version
-      Self::Arc(s) => *self = s[..index].to_owned().into(),
+      Self::Arc { start, end, .. } => {
+        let index = u32::try_from(index)
+          .expect("FastString::truncate: index is longer than u32::MAX bytes");
+        *end = *start + index;
+      }
+      // No `'static` UTF-16 buffer exists for an arbitrary truncation point, so (like
+      // the `Arc` variant used to before it got the O(1) path above) we just take
+      // ownership of the truncated prefix; this allocates but never leaks.
+      Self::StaticUtf16(data) => *self = data.utf8[..index].to_owned().into(),
+      Self::Inline { len, .. } => *len = index as u8,
+    }
+  }
+
+  /// Returns the byte index of the first occurrence of `needle`, if any.
+  pub fn find(&self, needle: &str) -> Option<usize> {
+    self.as_str().find(needle)
+  }
+
+  /// Returns the byte index of the last occurrence of `needle`, if any.
+  pub fn rfind(&self, needle: &str) -> Option<usize> {
+    self.as_str().rfind(needle)
+  }
+
+  /// Splits on `byte`, yielding sub-strings built with [`Self::slice`]: each piece of an
+  /// `Arc`-backed source reuses the same allocation, and pieces of a `Static`/
+  /// `StaticAscii` source stay static, so for those sources the iterator is lazy and
+  /// allocation-free. Pieces of a `StaticUtf16` source are copied, as in [`Self::slice`].
+  ///
+  /// `byte` must be ASCII: a non-ASCII byte could match a UTF-8 continuation byte in
+  /// the middle of a multi-byte char, which would hand [`Self::slice`] a non-char-boundary
+  /// index and panic.
+  pub fn split(&self, byte: u8) -> Split {
+    assert!(byte.is_ascii(), "FastString::split delimiter must be ASCII");
+    Split { source: self.clone(), delim: byte, pos: 0, done: false }
+  }
+
+  /// Returns the text after `prefix` if `self` starts with it, as a [`Self::slice`].
+  pub fn strip_prefix(&self, prefix: &str) -> Option<FastString> {
+    self
+      .as_bytes()
+      .starts_with(prefix.as_bytes())
+      .then(|| self.slice(prefix.len()..))
+  }
+
+  /// Returns the text before `suffix` if `self` ends with it, as a [`Self::slice`].
+  pub fn strip_suffix(&self, suffix: &str) -> Option<FastString> {
+    let len = self.as_bytes().len();
+    self
+      .as_bytes()
+      .ends_with(suffix.as_bytes())
+      .then(|| self.slice(..len - suffix.len()))
+  }
+}
+
+/// Iterator returned by [`FastString::split`].
+pub struct Split {
+  source: FastString,
+  delim: u8,
+  pos: usize,
+  done: bool,
+}
+
+impl Iterator for Split {
+  type Item = FastString;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.done {
+      return None;
+    }
+    let rest = &self.source.as_bytes()[self.pos..];
+    match rest.iter().position(|&b| b == self.delim) {
+      Some(i) => {
+        let piece = self.source.slice(self.pos..self.pos + i);
+        self.pos += i + 1;
+        Some(piece)
+      }
+      None => {
+        self.done = true;
+        Some(self.source.slice(self.pos..))
+      }
     }
   }
 }
@@ -184,7 +411,8 @@ impl From<Url> for FastString {
 /// ASCII check.
 impl From<String> for FastString {
   fn from(value: String) -> Self {
-    FastString::Arc(value.into_boxed_str().into())
+    FastString::try_inline(&value)
+      .unwrap_or_else(|| FastString::from_arc(value.into_boxed_str().into()))
   }
 }
 
@@ -203,6 +431,27 @@ macro_rules! fast {
   };
 }
 
+/// Include a fast string in the binary, transcoding it to UTF-16 on first use so it can
+/// take the zero-copy two-byte path in [`FastString::v8`]. Use this instead of
+/// [`include_fast_string!`] for static text (e.g. i18n message bundles) that isn't
+/// guaranteed to be ASCII.
+#[macro_export]
+macro_rules! include_fast_utf16_string {
+  ($file:literal) => {{
+    static DATA: ::std::sync::OnceLock<$crate::Utf16Static> =
+      ::std::sync::OnceLock::new();
+    $crate::FastString::from_static_utf16(DATA.get_or_init(|| $crate::Utf16Static {
+      utf8: include_str!($file),
+      utf16: ::std::boxed::Box::leak(
+        include_str!($file)
+          .encode_utf16()
+          .collect::<::std::vec::Vec<u16>>()
+          .into_boxed_slice(),
+      ),
+    }))
+  }};
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -224,5 +473,156 @@ mod tests {
     let mut code: FastString = FastString::from_arc(arc_str);
     code.truncate(3);
     assert_eq!(s, code.as_ref());
+
+    const DATA: Utf16Static = Utf16Static {
+      utf8: "中ab",
+      utf16: &[0x4e2d, b'a' as u16, b'b' as u16],
+    };
+    let mut code: FastString = FastString::from_static_utf16(&DATA);
+    code.truncate("中a".len());
+    assert_eq!("中a", code.as_ref());
+  }
+
+  #[test]
+  fn static_utf16() {
+    const DATA: Utf16Static = Utf16Static {
+      utf8: "中文",
+      utf16: &[0x4e2d, 0x6587],
+    };
+    let code = FastString::from_static_utf16(&DATA);
+    assert_eq!("中文", code.as_str());
+    assert_eq!("中文".as_bytes(), code.as_bytes());
+
+    // Truncating/slicing a `StaticUtf16` falls back to an owned variant rather
+    // than leaking a transcoded buffer.
+    let tail = code.slice(3..);
+    assert_eq!("文", tail.as_str());
+    assert!(matches!(tail, FastString::Inline { .. }));
+  }
+
+  #[test]
+  fn inline() {
+    let short: FastString = "a short string".to_owned().into();
+    assert!(matches!(short, FastString::Inline { .. }));
+    assert_eq!("a short string", short.as_str());
+
+    // Exactly at the inline boundary: stays inline, no `Arc` allocation.
+    let at_boundary = "a".repeat(FastString::INLINE_MAX);
+    let code: FastString = at_boundary.clone().into();
+    assert!(matches!(code, FastString::Inline { .. }));
+    assert_eq!(at_boundary, code.as_str());
+
+    // One byte past the boundary: falls back to `Arc`.
+    let over_boundary = "a".repeat(FastString::INLINE_MAX + 1);
+    let code: FastString = over_boundary.clone().into();
+    assert!(matches!(code, FastString::Arc { .. }));
+    assert_eq!(over_boundary, code.as_str());
+
+    // A multi-byte char straddling the boundary must still round-trip correctly.
+    // The 2-byte 'α' sits at bytes INLINE_MAX - 1..INLINE_MAX + 1, across the limit.
+    let multibyte = format!("{}α", "a".repeat(FastString::INLINE_MAX - 1));
+    let code: FastString = multibyte.clone().into();
+    assert_eq!(multibyte, code.as_str());
+  }
+
+  #[test]
+  fn slice() {
+    let code = FastString::from_arc(Arc::from("hello world"));
+    let hello = code.slice(..5);
+    let world = code.slice(6..);
+    assert_eq!("hello", hello.as_str());
+    assert_eq!("world", world.as_str());
+
+    // Slicing a slice should still share the original allocation.
+    let ello = hello.slice(1..);
+    assert_eq!("ello", ello.as_str());
+    match (&code, &ello) {
+      (FastString::Arc { data: a, .. }, FastString::Arc { data: b, .. }) => {
+        assert!(Arc::ptr_eq(a, b));
+      }
+      _ => unreachable!(),
+    }
+  }
+
+  #[test]
+  fn find_rfind() {
+    let code = FastString::from_arc(Arc::from("a,b,c"));
+    assert_eq!(Some(1), code.find(","));
+    assert_eq!(Some(3), code.rfind(","));
+    assert_eq!(None, code.find(";"));
+  }
+
+  #[test]
+  fn split() {
+    let code = FastString::from_arc(Arc::from("a,b,c"));
+    let parts: Vec<_> = code.split(b',').collect();
+    assert_eq!(vec!["a", "b", "c"], parts.iter().map(|p| p.as_str()).collect::<Vec<_>>());
+
+    // Each piece shares the original allocation.
+    match (&code, &parts[1]) {
+      (FastString::Arc { data: a, .. }, FastString::Arc { data: b, .. }) => {
+        assert!(Arc::ptr_eq(a, b));
+      }
+      _ => unreachable!(),
+    }
+
+    // No delimiter: a single piece covering the whole string.
+    let code = FastString::from_arc(Arc::from("abc"));
+    let parts: Vec<_> = code.split(b',').collect();
+    assert_eq!(vec!["abc"], parts.iter().map(|p| p.as_str()).collect::<Vec<_>>());
+
+    // Multi-byte chars elsewhere in the string must not confuse the byte scan.
+    let code = FastString::from_arc(Arc::from("café,noël"));
+    let parts: Vec<_> = code.split(b',').collect();
+    assert_eq!(
+      vec!["café", "noël"],
+      parts.iter().map(|p| p.as_str()).collect::<Vec<_>>()
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "FastString::split delimiter must be ASCII")]
+  fn split_rejects_non_ascii_delimiter() {
+    // A non-ASCII delimiter could match a UTF-8 continuation byte in the middle of a
+    // multi-byte char (e.g. 0xA9 inside "café"'s trailing 'é'), which would hand
+    // `slice()` a non-char-boundary index. Reject it up front instead.
+    FastString::from_arc(Arc::from("café")).split(0xA9);
+  }
+
+  #[test]
+  fn strip_prefix_suffix() {
+    let code = FastString::from_arc(Arc::from("file:///mod.ts"));
+    assert_eq!("///mod.ts", code.strip_prefix("file:").unwrap().as_str());
+    assert_eq!("file:///mod", code.strip_suffix(".ts").unwrap().as_str());
+    assert!(code.strip_prefix("https:").is_none());
+    assert!(code.strip_suffix(".js").is_none());
+  }
+
+  #[test]
+  fn from_utf8() {
+    let code = FastString::from_utf8(b"hello".to_vec()).unwrap();
+    assert_eq!("hello", code.as_str());
+
+    assert!(FastString::from_utf8(vec![0xff, 0xfe]).is_err());
+  }
+
+  #[test]
+  fn from_bytes_lossy() {
+    let code = FastString::from_bytes_lossy(b"hello".to_vec());
+    assert_eq!("hello", code.as_str());
+
+    let code = FastString::from_bytes_lossy(vec![b'a', 0xff, b'b']);
+    assert_eq!("a\u{fffd}b", code.as_str());
+  }
+
+  #[test]
+  fn from_static_bytes() {
+    let code = FastString::from_static_bytes(b"hello");
+    assert!(matches!(code, FastString::StaticAscii(_)));
+    assert_eq!("hello", code.as_str());
+
+    let code = FastString::from_static_bytes("héllo".as_bytes());
+    assert!(matches!(code, FastString::Static(_)));
+    assert_eq!("héllo", code.as_str());
   }
 }
\ No newline at end of file